@@ -14,4 +14,10 @@ pub enum Error {
     ServiceNotExist,
     #[error("no such entry found in `Dumpsys`")]
     NoEntryFound,
+    #[error("dump did not complete before the configured timeout")]
+    Timeout,
+    #[error("dump was cancelled before it completed")]
+    Cancelled,
+    #[error("dump worker thread closed unexpectedly: {0:?}")]
+    WorkerClosed(Option<String>),
 }
@@ -1,22 +1,27 @@
 pub mod error;
+mod pool;
 mod task_thread;
 
 use std::{
     self,
+    any::Any,
     collections::HashMap,
     hash::BuildHasherDefault,
-    io::{self, PipeWriter, Read as _},
+    io::{self, BufRead, PipeWriter, Read},
     ops::Deref,
     sync::{
-        Arc,
-        atomic::{AtomicI32, Ordering},
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicI32, Ordering},
+        mpsc::SendError,
     },
+    thread,
+    time::{Duration, Instant},
 };
 
 use rsbinder::{ProcessState, SIBinder, StatusCode, hub};
 use twox_hash::XxHash3_64;
 
-use crate::{error::Error, task_thread::TaskThread};
+use crate::{error::Error, pool::WorkerPool, task_thread::TaskThread};
 
 type Result<T, E = crate::error::Error> = core::result::Result<T, E>;
 
@@ -46,6 +51,27 @@ pub fn dump<S: AsRef<str>>(service_name: S, args: &[&str]) -> Result<String> {
     dump_inner(&task_thread, service, args)
 }
 
+/// Like [`dump`], but gives up with [`Error::Timeout`] instead of blocking
+/// forever if the service hasn't produced a full dump within `timeout`.
+pub fn dump_with_timeout<S: AsRef<str>>(service_name: S, args: &[&str], timeout: Duration) -> Result<String> {
+    _ = ProcessState::init_default();
+
+    let task_thread = TaskThread::spawn();
+
+    let service = hub::get_service(service_name.as_ref()).ok_or(Error::ServiceNotExist)?;
+
+    let (reader, status) = submit_dump(&task_thread, service, args)?;
+    let result = finish_dump_supervised(reader, status, Some(Instant::now() + timeout), &DumpCancelHandle::new());
+
+    // Dropping `task_thread` joins its worker, which may still be stuck in
+    // `proxy.dump` after a timeout, so drop it off the caller's thread.
+    if matches!(result, Err(Error::Timeout)) {
+        thread::spawn(move || drop(task_thread));
+    }
+
+    result
+}
+
 #[repr(transparent)]
 struct DumpArgs {
     inner: Box<[String]>,
@@ -66,13 +92,147 @@ impl Deref for DumpArgs {
     }
 }
 
-type StatusI32Slot = Arc<AtomicI32>;
+/// Per-dump completion state, shared between the caller and whichever
+/// worker thread ends up running `proxy.dump`.
+///
+/// `panic` is populated instead of `code` if the worker panicked partway
+/// through the dump, so a caller can tell that apart from a legitimately
+/// empty, successful dump.
+struct DumpStatus {
+    code: AtomicI32,
+    panic: Mutex<Option<String>>,
+}
+
+impl DumpStatus {
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            code: AtomicI32::new(i32::from(StatusCode::Ok)),
+            panic: Mutex::new(None),
+        })
+    }
+
+    /// Turns a worker panic or non-`Ok` `StatusCode` into an `Err`; `Ok(())`
+    /// means the dump completed (or hasn't reported a failure yet).
+    ///
+    /// Non-consuming: calling this again after a panic still reports it, so
+    /// [`DumpReader::status`] and [`DumpReader::finish`] agree regardless of
+    /// how many times or in what order a caller calls them.
+    fn check(&self) -> Result<()> {
+        if let Some(panic_msg) = self.panic.lock().unwrap().clone() {
+            return Err(Error::WorkerClosed(Some(panic_msg)));
+        }
+
+        let status_code = StatusCode::from(self.code.load(Ordering::Relaxed));
+        if !matches!(status_code, StatusCode::Ok) {
+            Err(status_code)?;
+        }
+
+        Ok(())
+    }
+}
+
+type StatusSlot = Arc<DumpStatus>;
+
+/// Renders a `std::panic::catch_unwind` payload as a displayable message,
+/// falling back to a generic description for payloads that aren't a `&str`
+/// or `String` (the two types `panic!` produces).
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        (*msg).to_owned()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "worker thread panicked with a non-string payload".to_owned()
+    }
+}
 
 enum Task {
-    Dump(DumpArgs, PipeWriter, SIBinder, StatusI32Slot),
+    Dump(DumpArgs, PipeWriter, SIBinder, StatusSlot),
     Shutdown,
 }
 
+/// Anything that can accept a [`Task`], so `submit_dump` works the same way
+/// whether it is handed a single-threaded [`TaskThread`] or a [`WorkerPool`].
+trait TaskSender {
+    fn send(&self, task: Task) -> Result<(), SendError<Task>>;
+
+    /// Whether every worker backing this sender has already exited, so a
+    /// new dump can be rejected up front instead of only after a failed
+    /// `send`.
+    fn is_closed(&self) -> bool;
+}
+
+impl TaskSender for TaskThread {
+    fn send(&self, task: Task) -> Result<(), SendError<Task>> {
+        TaskThread::send(self, task)
+    }
+
+    fn is_closed(&self) -> bool {
+        TaskThread::is_closed(self)
+    }
+}
+
+impl TaskSender for WorkerPool {
+    fn send(&self, task: Task) -> Result<(), SendError<Task>> {
+        WorkerPool::send(self, task)
+    }
+
+    fn is_closed(&self) -> bool {
+        WorkerPool::is_closed(self)
+    }
+}
+
+/// A handle that can request an in-flight dump give up early, without
+/// waiting for its timeout (if any) to elapse.
+///
+/// Cloning shares the same underlying dump: cancelling a clone cancels the
+/// original. Kept crate-private: callers reach cancellation through
+/// [`DumpHandle::cancel`], which forwards to one of these internally.
+#[derive(Clone, Default)]
+struct DumpCancelHandle(Arc<AtomicBool>);
+
+impl DumpCancelHandle {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation; the in-flight dump returns [`Error::Cancelled`]
+    /// the next time it polls this handle.
+    fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// A dump spawned via `dump_cancellable`, running to completion on its own
+/// thread so the caller can keep doing other work until it calls [`join`]
+/// (or [`cancel`] from yet another thread).
+///
+/// [`join`]: DumpHandle::join
+/// [`cancel`]: DumpHandle::cancel
+pub struct DumpHandle {
+    cancel: DumpCancelHandle,
+    worker: thread::JoinHandle<Result<String>>,
+}
+
+impl DumpHandle {
+    /// Requests the dump stop waiting early; [`DumpHandle::join`] will then
+    /// return [`Error::Cancelled`].
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Blocks until the dump finishes or is cancelled; there is no timeout.
+    pub fn join(self) -> Result<String> {
+        self.worker
+            .join()
+            .unwrap_or_else(|_| Err(io::Error::other("dump watcher thread panicked").into()))
+    }
+}
+
 /// Single retrieved existing services.
 ///
 /// Like [`Dumpsys`], but use a task_thread exclusively.
@@ -114,26 +274,118 @@ impl BoundDumpsys {
     pub fn dump(&self, args: &[&str]) -> Result<String> {
         dump_inner(&self.task_thread, self.service.clone(), args)
     }
+
+    /// Async counterpart of [`BoundDumpsys::dump`].
+    #[cfg(feature = "async")]
+    pub async fn dump_async(&self, args: &[&str]) -> Result<String> {
+        dump_inner_async(&self.task_thread, self.service.clone(), args).await
+    }
+
+    /// Like [`BoundDumpsys::dump`], but gives up with [`Error::Timeout`]
+    /// instead of blocking forever on a hung service.
+    pub fn dump_with_timeout(&self, args: &[&str], timeout: Duration) -> Result<String> {
+        let (reader, status) = submit_dump(&self.task_thread, self.service.clone(), args)?;
+        finish_dump_supervised(reader, status, Some(Instant::now() + timeout), &DumpCancelHandle::new())
+    }
+
+    /// Like [`BoundDumpsys::dump`], but runs on its own thread and returns a
+    /// [`DumpHandle`] immediately, letting the caller cancel it from
+    /// elsewhere before it completes.
+    pub fn dump_cancellable(&self, args: &[&str]) -> Result<DumpHandle> {
+        let (reader, status) = submit_dump(&self.task_thread, self.service.clone(), args)?;
+
+        let cancel = DumpCancelHandle::new();
+        let worker = {
+            let cancel = cancel.clone();
+            thread::spawn(move || finish_dump_supervised(reader, status, None, &cancel))
+        };
+
+        Ok(DumpHandle { cancel, worker })
+    }
+
+    /// Like [`BoundDumpsys::dump`], but returns a [`DumpReader`] instead of
+    /// buffering the whole output into a `String`, so callers can parse
+    /// incrementally (and stop early) instead of waiting for the full dump.
+    pub fn dump_stream(&self, args: &[&str]) -> Result<DumpReader> {
+        stream_dump(&self.task_thread, self.service.clone(), args)
+    }
 }
 
 type XxHashMap<K, V> = HashMap<K, V, BuildHasherDefault<XxHash3_64>>;
 
+/// Builder for [`Dumpsys`], letting callers size the worker pool that backs
+/// [`Dumpsys::dump`] and [`Dumpsys::dump_many`].
+pub struct DumpsysBuilder {
+    workers: usize,
+    max_concurrent_dumps: Option<usize>,
+    default_timeout: Option<Duration>,
+}
+
+impl DumpsysBuilder {
+    pub fn new() -> Self {
+        Self {
+            workers: thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            max_concurrent_dumps: None,
+            default_timeout: None,
+        }
+    }
+
+    /// Overrides the worker pool size. Defaults to
+    /// [`std::thread::available_parallelism`].
+    pub fn workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    /// Caps the number of `proxy.dump` calls in flight at once, independent
+    /// of [`DumpsysBuilder::workers`]. Defaults to the worker count, i.e. no
+    /// cap beyond what the thread count already imposes; set this lower to
+    /// throttle binder transaction concurrency without also shrinking the
+    /// pool that drains queued [`Dumpsys::dump_many`] requests.
+    pub fn max_concurrent_dumps(mut self, max_concurrent_dumps: usize) -> Self {
+        self.max_concurrent_dumps = Some(max_concurrent_dumps.max(1));
+        self
+    }
+
+    /// Applies `timeout` to every [`Dumpsys::dump`]/[`Dumpsys::dump_many`]
+    /// call, as if made through [`Dumpsys::dump_with_timeout`]. Unset by
+    /// default, meaning those calls can block forever on a hung service.
+    pub fn default_timeout(mut self, timeout: Duration) -> Self {
+        self.default_timeout = Some(timeout);
+        self
+    }
+
+    pub fn build(self) -> Result<Dumpsys> {
+        _ = ProcessState::init_default();
+
+        let max_concurrent_dumps = self.max_concurrent_dumps.unwrap_or(self.workers);
+
+        Ok(Dumpsys {
+            map: XxHashMap::default(),
+            pool: WorkerPool::spawn(self.workers, max_concurrent_dumps),
+            default_timeout: self.default_timeout,
+        })
+    }
+}
+
+impl Default for DumpsysBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Retrieved existing services.
 ///
 /// Drop [`Dumpsys`] will exit the background pipeing thread
 pub struct Dumpsys {
     map: XxHashMap<Box<str>, SIBinder>,
-    task_thread: TaskThread,
+    pool: WorkerPool,
+    default_timeout: Option<Duration>,
 }
 
 impl Dumpsys {
     pub fn new() -> Result<Self> {
-        _ = ProcessState::init_default();
-
-        Ok(Self {
-            map: XxHashMap::default(),
-            task_thread: TaskThread::spawn(),
-        })
+        DumpsysBuilder::new().build()
     }
 
     /// Retrieve an existing service and save it for dump, blocking for a few seconds if it doesn't yet exist.
@@ -175,33 +427,417 @@ impl Dumpsys {
     pub fn dump<S: AsRef<str>>(&mut self, service_name: S, args: &[&str]) -> Result<String> {
         let service_name = service_name.as_ref();
 
+        let service = self.map.get(service_name).ok_or(Error::NoEntryFound)?.clone();
+
+        match self.default_timeout {
+            Some(timeout) => {
+                let (reader, status) = submit_dump(&self.pool, service, args)?;
+                finish_dump_supervised(reader, status, Some(Instant::now() + timeout), &DumpCancelHandle::new())
+            }
+            None => dump_inner(&self.pool, service, args),
+        }
+    }
+
+    /// Like [`Dumpsys::dump`], but gives up with [`Error::Timeout`] instead
+    /// of blocking forever, ignoring any [`DumpsysBuilder::default_timeout`].
+    pub fn dump_with_timeout<S: AsRef<str>>(
+        &self,
+        service_name: S,
+        args: &[&str],
+        timeout: Duration,
+    ) -> Result<String> {
+        let service_name = service_name.as_ref();
+
+        let service = self.map.get(service_name).ok_or(Error::NoEntryFound)?.clone();
+
+        let (reader, status) = submit_dump(&self.pool, service, args)?;
+        finish_dump_supervised(reader, status, Some(Instant::now() + timeout), &DumpCancelHandle::new())
+    }
+
+    /// Like [`Dumpsys::dump`], but runs on its own thread and returns a
+    /// [`DumpHandle`] immediately, letting the caller cancel it from
+    /// elsewhere before it completes.
+    pub fn dump_cancellable<S: AsRef<str>>(&self, service_name: S, args: &[&str]) -> Result<DumpHandle> {
+        let service_name = service_name.as_ref();
+
+        let service = self.map.get(service_name).ok_or(Error::NoEntryFound)?.clone();
+
+        let (reader, status) = submit_dump(&self.pool, service, args)?;
+
+        let cancel = DumpCancelHandle::new();
+        let worker = {
+            let cancel = cancel.clone();
+            thread::spawn(move || finish_dump_supervised(reader, status, None, &cancel))
+        };
+
+        Ok(DumpHandle { cancel, worker })
+    }
+
+    /// Async counterpart of [`Dumpsys::dump`].
+    #[cfg(feature = "async")]
+    pub async fn dump_async<S: AsRef<str>>(&self, service_name: S, args: &[&str]) -> Result<String> {
+        let service_name = service_name.as_ref();
+
         let service = self.map.get(service_name).ok_or(Error::NoEntryFound)?;
 
-        dump_inner(&self.task_thread, service.clone(), args)
+        dump_inner_async(&self.pool, service.clone(), args).await
+    }
+
+    /// Like [`Dumpsys::dump`], but returns a [`DumpReader`] instead of
+    /// buffering the whole output into a `String`, so callers can parse
+    /// incrementally (and stop early) instead of waiting for the full dump.
+    pub fn dump_stream<S: AsRef<str>>(&self, service_name: S, args: &[&str]) -> Result<DumpReader> {
+        let service_name = service_name.as_ref();
+
+        let service = self.map.get(service_name).ok_or(Error::NoEntryFound)?.clone();
+
+        stream_dump(&self.pool, service, args)
+    }
+
+    /// Dump several already-[inserted][Dumpsys::insert_service] services,
+    /// fanning the requests out across the worker pool instead of serializing
+    /// one `proxy.dump` at a time. Results are returned in `requests` order.
+    pub fn dump_many(&self, requests: &[(&str, &[&str])]) -> Vec<Result<String>> {
+        self.dump_many_iter(requests).collect()
+    }
+
+    /// Iterator-returning variant of [`Dumpsys::dump_many`].
+    ///
+    /// Every request is submitted to the worker pool up front; iterating
+    /// then blocks only as each result's pipe reaches EOF, so results can be
+    /// consumed as they complete rather than all-at-once.
+    pub fn dump_many_iter<'a>(
+        &'a self,
+        requests: &'a [(&str, &[&str])],
+    ) -> impl Iterator<Item = Result<String>> + 'a {
+        requests
+            .iter()
+            .map(|(service_name, args)| {
+                let service = self.map.get(*service_name).ok_or(Error::NoEntryFound)?;
+                submit_dump(&self.pool, service.clone(), args)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(move |pending| {
+                pending.and_then(|(reader, status)| match self.default_timeout {
+                    Some(timeout) => finish_dump_supervised(
+                        reader,
+                        status,
+                        Some(Instant::now() + timeout),
+                        &DumpCancelHandle::new(),
+                    ),
+                    None => finish_dump(reader, status),
+                })
+            })
     }
 }
 
-fn dump_inner(task_thread: &TaskThread, service: SIBinder, args: &[&str]) -> Result<String> {
-    let (mut reader, writer) = io::pipe()?;
+/// Sends a [`Task::Dump`] to `sender` and returns the pipe's read end together
+/// with the status slot `proxy.dump` will report into, without waiting for
+/// the dump to complete.
+fn submit_dump<T: TaskSender>(
+    sender: &T,
+    service: SIBinder,
+    args: &[&str],
+) -> Result<(io::PipeReader, StatusSlot)> {
+    if sender.is_closed() {
+        return Err(Error::WorkerClosed(None));
+    }
+
+    let (reader, writer) = io::pipe()?;
 
-    let status_i32 = Arc::new(AtomicI32::new(i32::from(StatusCode::Ok)));
+    let status = DumpStatus::new();
 
-    task_thread
+    sender
         .send(Task::Dump(
             DumpArgs::from_iter(args.iter().copied().map(String::from)),
             writer,
             service,
-            status_i32.clone(),
+            status.clone(),
         ))
-        .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "task_thread dropped receiver"))?;
+        .map_err(|_| Error::WorkerClosed(None))?;
 
-    let mut buf = String::new();
-    reader.read_to_string(&mut buf)?;
+    Ok((reader, status))
+}
+
+/// A streamed service dump.
+///
+/// Wraps the read end of the dump's pipe in a [`BufReader`][io::BufReader]
+/// so it can be consumed incrementally via [`Read`]/[`BufRead`] (including
+/// [`BufRead::lines`]) instead of buffering the whole output into a
+/// `String` up front. The binder status is propagated the same way as the
+/// buffered API: once the stream reaches EOF, a non-`Ok` `StatusCode` (or a
+/// worker panic) surfaces as an [`io::Error`] from the read call that hit
+/// it, rather than being swallowed as a truncated but "successful" read.
+pub struct DumpReader {
+    inner: io::BufReader<io::PipeReader>,
+    status: StatusSlot,
+    finished: bool,
+}
+
+impl DumpReader {
+    fn new(reader: io::PipeReader, status: StatusSlot) -> Self {
+        Self {
+            inner: io::BufReader::new(reader),
+            status,
+            finished: false,
+        }
+    }
+
+    fn mark_eof_and_check(&mut self) -> io::Result<()> {
+        if !self.finished {
+            self.finished = true;
+            self.status.check().map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    /// Drains any remaining output (if the stream wasn't already read to
+    /// EOF) and returns the binder status, or the worker's panic payload if
+    /// it died mid-dump.
+    pub fn finish(mut self) -> Result<()> {
+        if !self.finished {
+            io::copy(&mut self.inner, &mut io::sink())?;
+            self.finished = true;
+        }
+
+        self.status.check()
+    }
+
+    /// Returns the status observed so far, without draining the stream.
+    /// Useful to bail out of a [`BufRead::lines`] loop early once the
+    /// worker has already reported a failure.
+    pub fn status(&self) -> Result<()> {
+        self.status.check()
+    }
+}
+
+impl Read for DumpReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let n = self.inner.read(buf)?;
+        if n == 0 {
+            self.mark_eof_and_check()?;
+        }
+
+        Ok(n)
+    }
+}
 
-    let status_code = StatusCode::from(status_i32.load(Ordering::Relaxed));
-    if !matches!(status_code, StatusCode::Ok) {
-        Err(status_code)?;
+impl BufRead for DumpReader {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.inner.fill_buf()?.is_empty() {
+            self.mark_eof_and_check()?;
+        }
+
+        // `buffer()` just returns what `fill_buf` above already filled,
+        // instead of issuing a second (redundant) fill.
+        Ok(self.inner.buffer())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+    }
+}
+
+/// Recovers the [`Error`] an [`io::Error`] was built from via
+/// `io::Error::other`, for errors bubbled up through [`DumpReader`]'s
+/// [`Read`] impl. Falls back to wrapping the plain I/O error otherwise.
+fn unwrap_stream_error(e: io::Error) -> Error {
+    match e.into_inner() {
+        Some(inner) => match inner.downcast::<Error>() {
+            Ok(err) => *err,
+            Err(inner) => Error::IO(io::Error::other(inner)),
+        },
+        None => Error::IO(e),
     }
+}
+
+/// Submits a dump and wraps its pipe in a [`DumpReader`], without waiting
+/// for it to complete.
+fn stream_dump<T: TaskSender>(sender: &T, service: SIBinder, args: &[&str]) -> Result<DumpReader> {
+    let (reader, status) = submit_dump(sender, service, args)?;
+
+    Ok(DumpReader::new(reader, status))
+}
+
+/// Blocks until `reader` reaches EOF, then checks `status` exactly as the
+/// worker left it, turning a non-`Ok` `StatusCode` into an `Err`.
+///
+/// A panicked worker drops its end of the pipe too, so `reader` still sees a
+/// clean EOF; `status.panic` is what lets this tell that apart from a
+/// legitimately empty, successful dump.
+fn finish_dump(reader: io::PipeReader, status: StatusSlot) -> Result<String> {
+    let mut reader = DumpReader::new(reader, status);
+
+    let mut buf = String::new();
+    reader.read_to_string(&mut buf).map_err(unwrap_stream_error)?;
+
+    Ok(buf)
+}
+
+/// Tail of [`dump_inner_async`]: turns the
+/// final `status` into an `Err` if the worker panicked or reported a
+/// non-`Ok` `StatusCode`, otherwise returns the read bytes as-is.
+#[cfg(feature = "async")]
+fn check_status(status: StatusSlot, buf: String) -> Result<String> {
+    status.check()?;
 
     Ok(buf)
 }
+
+fn dump_inner<T: TaskSender>(sender: &T, service: SIBinder, args: &[&str]) -> Result<String> {
+    let (reader, status_i32) = submit_dump(sender, service, args)?;
+
+    finish_dump(reader, status_i32)
+}
+
+/// How often [`finish_dump_supervised`] wakes up to re-check `cancel`/the
+/// deadline between [`poll`][libc::poll] calls, so a `DumpCancelHandle`
+/// cancellation is noticed promptly instead of only once the pipe next has
+/// data.
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Puts `fd` in non-blocking mode, so reads on it return
+/// [`io::ErrorKind::WouldBlock`] instead of blocking when no data is ready.
+fn set_nonblocking(fd: std::os::fd::RawFd) -> io::Result<()> {
+    // SAFETY: `fd` is a valid, open pipe read end for the duration of this
+    // call; `fcntl` with `F_GETFL`/`F_SETFL` only inspects/modifies its
+    // file status flags.
+    unsafe {
+        let flags = libc::fcntl(fd, libc::F_GETFL);
+        if flags < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+
+    Ok(())
+}
+
+/// Blocks up to `timeout` for `fd` to become readable (or hit EOF), without
+/// reading from it. Returns `false` on a plain timeout.
+fn poll_readable(fd: std::os::fd::RawFd, timeout: Duration) -> io::Result<bool> {
+    let mut pfd = libc::pollfd {
+        fd,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let timeout_ms = i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX);
+
+    // SAFETY: `pfd` is a single, valid, stack-local `pollfd`.
+    let ready = unsafe { libc::poll(&mut pfd, 1, timeout_ms) };
+    match ready {
+        0 => Ok(false),
+        n if n > 0 => Ok(true),
+        _ => {
+            let err = io::Error::last_os_error();
+            match err.kind() {
+                // A signal interrupted the wait; the caller's loop will
+                // just re-poll with the remaining budget.
+                io::ErrorKind::Interrupted => Ok(false),
+                _ => Err(err),
+            }
+        }
+    }
+}
+
+/// Drives `reader` to completion like [`finish_dump`], but reads it directly
+/// with a deadline instead of blocking on it, so the caller can poll `cancel`
+/// and an optional overall `deadline` without a dedicated reader thread per
+/// call. On timeout or cancellation, `reader` is dropped before returning,
+/// closing the pipe's read end.
+///
+/// This is best-effort, not a guarantee the worker is freed: if the service
+/// is actively writing and blocked on a full pipe, closing the read end
+/// makes its write fail and the worker moves on to its next `Task`. But a
+/// `proxy.dump` that is hung in the binder transaction itself (the case this
+/// request targets, e.g. a service that never replies) is blocked in the
+/// kernel, not on the pipe, and dropping `reader` does nothing to unblock
+/// it — that worker stays wedged on the hung service regardless of this
+/// timeout, and enough hangs will still exhaust the pool.
+fn finish_dump_supervised(
+    mut reader: io::PipeReader,
+    status: StatusSlot,
+    deadline: Option<Instant>,
+    cancel: &DumpCancelHandle,
+) -> Result<String> {
+    use std::os::fd::AsRawFd as _;
+
+    let fd = reader.as_raw_fd();
+    set_nonblocking(fd)?;
+
+    let mut raw = Vec::new();
+    let mut chunk = [0u8; 8192];
+
+    loop {
+        if cancel.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+
+        let wait = match deadline {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Err(Error::Timeout);
+                }
+                remaining.min(CANCEL_POLL_INTERVAL)
+            }
+            None => CANCEL_POLL_INTERVAL,
+        };
+
+        if !poll_readable(fd, wait)? {
+            continue;
+        }
+
+        loop {
+            match reader.read(&mut chunk) {
+                Ok(0) => {
+                    status.check()?;
+                    return String::from_utf8(raw)
+                        .map_err(|e| Error::IO(io::Error::new(io::ErrorKind::InvalidData, e)));
+                }
+                Ok(n) => raw.extend_from_slice(&chunk[..n]),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// Non-blocking counterpart of [`dump_inner`].
+///
+/// The background worker still blocks on `proxy.dump`, but the pipe's read
+/// end is registered with async-io's reactor instead of being read
+/// synchronously, so a slow dump never ties up the caller's executor thread.
+#[cfg(feature = "async")]
+async fn dump_inner_async<T: TaskSender>(sender: &T, service: SIBinder, args: &[&str]) -> Result<String> {
+    use async_io::Async;
+    use futures_lite::AsyncReadExt as _;
+
+    let (reader, status_i32) = submit_dump(sender, service, args)?;
+
+    let mut reader = Async::new(reader)?;
+    let mut buf = String::new();
+    reader.read_to_string(&mut buf).await?;
+
+    check_status(status_i32, buf)
+}
+
+/// Async counterpart of [`dump`].
+#[cfg(feature = "async")]
+pub async fn dump_async<S: AsRef<str>>(service_name: S, args: &[&str]) -> Result<String> {
+    _ = ProcessState::init_default();
+
+    let task_thread = TaskThread::spawn();
+
+    let service = hub::get_service(service_name.as_ref()).ok_or(Error::ServiceNotExist)?;
+
+    dump_inner_async(&task_thread, service, args).await
+}
@@ -0,0 +1,117 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, SendError, Sender, SyncSender},
+    },
+    thread::{self, JoinHandle},
+};
+
+use crate::{Task, panic_message};
+
+/// A fixed-size pool of worker threads draining a shared [`Task`] queue.
+///
+/// `workers` threads are spawned up front, but in-flight `proxy.dump` calls
+/// are additionally capped jobserver-style: `max_concurrent` tokens are
+/// minted, and a worker must acquire one before dequeuing a task, so the
+/// concurrency limit can be tuned independently of (and below) the thread
+/// count.
+pub struct WorkerPool {
+    tx: Sender<Task>,
+    handles: Vec<JoinHandle<()>>,
+    alive: Arc<AtomicUsize>,
+}
+
+impl WorkerPool {
+    pub fn spawn(workers: usize, max_concurrent: usize) -> Self {
+        let workers = workers.max(1);
+        let max_concurrent = max_concurrent.max(1);
+
+        let (tx, rx) = mpsc::channel();
+        let rx = Arc::new(Mutex::new(rx));
+
+        let (token_tx, token_rx) = mpsc::sync_channel(max_concurrent);
+        for _ in 0..max_concurrent {
+            let _ = token_tx.send(());
+        }
+        let token_rx = Arc::new(Mutex::new(token_rx));
+
+        let alive = Arc::new(AtomicUsize::new(workers));
+
+        let handles = (0..workers)
+            .map(|_| Self::spawn_worker(rx.clone(), token_tx.clone(), token_rx.clone(), alive.clone()))
+            .collect();
+
+        Self { tx, handles, alive }
+    }
+
+    fn spawn_worker(
+        rx: Arc<Mutex<Receiver<Task>>>,
+        token_tx: SyncSender<()>,
+        token_rx: Arc<Mutex<Receiver<()>>>,
+        alive: Arc<AtomicUsize>,
+    ) -> JoinHandle<()> {
+        thread::spawn(move || {
+            loop {
+                // Wait for a free token before dequeuing, so idle workers
+                // never push in-flight dumps past `max_concurrent`.
+                if token_rx.lock().unwrap().recv().is_err() {
+                    break;
+                }
+
+                let task = rx.lock().unwrap().recv();
+                let (args, writer, service, status) = match task {
+                    Ok(Task::Dump(a, w, s, e)) => (a, w, s, e),
+                    Ok(Task::Shutdown) | Err(_) => {
+                        let _ = token_tx.send(());
+                        break;
+                    }
+                };
+
+                // continue drops writer, reader will get an EOF
+                let Some(proxy) = service.as_proxy() else {
+                    let _ = token_tx.send(());
+                    continue;
+                };
+
+                // A panic inside `proxy.dump` is caught here instead of
+                // taking this worker down with it, so the caller learns
+                // about it via `Error::WorkerClosed` rather than silently
+                // seeing an empty dump.
+                match panic::catch_unwind(AssertUnwindSafe(|| proxy.dump(writer, &args))) {
+                    Ok(Ok(())) => {}
+                    // if failed then return the StatusCode back to calling thread
+                    Ok(Err(e)) => status.code.store(i32::from(e), Ordering::Relaxed),
+                    Err(payload) => *status.panic.lock().unwrap() = Some(panic_message(payload)),
+                }
+
+                let _ = token_tx.send(());
+            }
+
+            alive.fetch_sub(1, Ordering::Release);
+        })
+    }
+
+    #[inline(always)]
+    pub fn send(&self, t: Task) -> Result<(), SendError<Task>> {
+        self.tx.send(t)
+    }
+
+    /// Whether every worker in the pool has exited, so the pool can no
+    /// longer make progress on any dump.
+    pub fn is_closed(&self) -> bool {
+        self.alive.load(Ordering::Acquire) == 0
+    }
+}
+
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        for _ in &self.handles {
+            let _ = self.tx.send(Task::Shutdown);
+        }
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
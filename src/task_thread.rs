@@ -1,51 +1,78 @@
 use std::{
+    panic::{self, AssertUnwindSafe},
     sync::{
-        atomic::Ordering,
+        Arc,
+        atomic::{AtomicBool, Ordering},
         mpsc::{self, SendError, Sender},
     },
-    thread,
+    thread::{self, JoinHandle},
 };
 
-use crate::Task;
+use crate::{Task, panic_message};
 
 pub struct TaskThread {
     tx: Sender<Task>,
+    closed: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
 }
 
 impl TaskThread {
     pub fn spawn() -> Self {
         let (tx, rx) = mpsc::channel();
+        let closed = Arc::new(AtomicBool::new(false));
 
-        thread::spawn(move || {
-            while let Ok(task) = rx.recv() {
-                let (args, writer, service, status) = match task {
-                    Task::Dump(a, w, s, e) => (a, w, s, e),
-                    Task::Shutdown => break,
-                };
-
-                // continue drops writer, reader will get an EOF
-                let Some(proxy) = service.as_proxy() else {
-                    continue;
-                };
-
-                // if failed then return the StatusCode back to calling thread
-                let _ = proxy
-                    .dump(writer, &args)
-                    .inspect_err(|e| status.store(i32::from(*e), Ordering::Relaxed));
+        let handle = thread::spawn({
+            let closed = closed.clone();
+            move || {
+                while let Ok(task) = rx.recv() {
+                    let (args, writer, service, status) = match task {
+                        Task::Dump(a, w, s, e) => (a, w, s, e),
+                        Task::Shutdown => break,
+                    };
+
+                    // continue drops writer, reader will get an EOF
+                    let Some(proxy) = service.as_proxy() else {
+                        continue;
+                    };
+
+                    // A panic inside `proxy.dump` is caught here instead of
+                    // taking the whole worker thread down with it, so the
+                    // caller learns about it via `Error::WorkerClosed`
+                    // rather than silently seeing an empty dump.
+                    match panic::catch_unwind(AssertUnwindSafe(|| proxy.dump(writer, &args))) {
+                        Ok(Ok(())) => {}
+                        // if failed then return the StatusCode back to calling thread
+                        Ok(Err(e)) => status.code.store(i32::from(e), Ordering::Relaxed),
+                        Err(payload) => *status.panic.lock().unwrap() = Some(panic_message(payload)),
+                    }
+                }
+
+                closed.store(true, Ordering::Release);
             }
         });
 
-        Self { tx }
+        Self {
+            tx,
+            closed,
+            handle: Some(handle),
+        }
     }
 
     #[inline(always)]
     pub fn send(&self, t: Task) -> Result<(), SendError<Task>> {
         self.tx.send(t)
     }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire) || self.handle.as_ref().is_some_and(JoinHandle::is_finished)
+    }
 }
 
 impl Drop for TaskThread {
     fn drop(&mut self) {
         let _ = self.tx.send(Task::Shutdown);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
     }
 }